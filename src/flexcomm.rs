@@ -19,28 +19,241 @@ pub enum Clock {
     /// FFRO
     Ffro,
 
-    /// `AUDIO_PLL`
-    AudioPll,
+    /// `AUDIO_PLL`, locking the PLL to the given output frequency if it isn't already
+    /// running at that rate
+    AudioPll(u32),
 
     /// MASTER
     Master,
 
-    /// FCn_FRG with Main clock source
-    FcnFrgMain,
+    /// FCn_FRG with Main clock source. The main clock rate is not known to this module, so
+    /// the FRG is left undivided (`MULT = 0`) whatever output frequency is given.
+    FcnFrgMain(u32),
 
-    /// FCn_FRG with Pll clock source
-    FcnFrgPll,
+    /// FCn_FRG with Pll clock source, divided down to the given output frequency
+    FcnFrgPll(u32),
 
-    /// FCn_FRG with Sfro clock source
-    FcnFrgSfro,
+    /// FCn_FRG with Sfro clock source, divided down to the given output frequency
+    FcnFrgSfro(u32),
 
-    /// FCn_FRG with Ffro clock source
-    FcnFrgFfro,
+    /// FCn_FRG with Ffro clock source, divided down to the given output frequency
+    FcnFrgFfro(u32),
 
     /// disabled
     None,
 }
 
+/// `SFRO` oscillator frequency
+const SFRO_FREQ_HZ: u32 = 16_000_000;
+
+/// `FFRO` oscillator frequency
+const FFRO_FREQ_HZ: u32 = 48_000_000;
+
+/// Frequency of the `MASTER` clock feeding the Flexcomm clock muxes.
+///
+/// TODO: this crate does not yet model the main clock tree; treat it as unknown until a
+/// `Clocks`-style configuration subsystem lands.
+fn master_clk_freq() -> u32 {
+    0
+}
+
+/// Frequency of the `AUDIO_PLL` clock feeding the Flexcomm clock muxes.
+fn audio_pll_freq() -> u32 {
+    audio_pll::frequency()
+}
+
+/// Audio PLL configuration.
+///
+/// `Clock::AudioPll(freq)` is selectable as a Flexcomm clock source everywhere in this
+/// module; `FlexcommLowLevel::enable` drives `configure` on demand so the PLL is locked to
+/// `freq` before the mux is switched over to it. This is what makes that source (and the
+/// I2S master clock it can drive) actually work, rather than selecting an unconfigured PLL.
+pub mod audio_pll {
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    /// Reference frequency the PLL fields are solved against, taken to be `SFRO`.
+    /// [`configure`] does not program the PLL's reference clock select.
+    const REF_FREQ_HZ: u32 = super::SFRO_FREQ_HZ;
+
+    /// Post-divider applied after the PLL's fractional multiplier to keep the VCO in range.
+    const POST_DIV: u32 = 2;
+
+    /// Rate published by the last successful [`configure`] call, `0` until then.
+    static FREQ_HZ: AtomicU32 = AtomicU32::new(0);
+
+    /// Requested audio PLL configuration.
+    #[derive(Copy, Clone, Debug)]
+    pub struct Config {
+        /// Desired audio PLL output frequency in Hz, e.g. a multiple of 44.1kHz or 48kHz.
+        pub freq: u32,
+    }
+
+    /// `MULT`/`NUM`/`DENOM` fields solved for a requested output frequency.
+    struct PllFields {
+        mult_int: u16,
+        num: u32,
+        denom: u32,
+    }
+
+    /// Solves `freq = (REF_FREQ_HZ * (mult_int + num/denom)) / POST_DIV` for the PLL's
+    /// integer and fractional multiplier fields, with `denom` fixed at `REF_FREQ_HZ` so
+    /// `num` is just the division remainder.
+    fn solve(freq: u32) -> PllFields {
+        let scaled = freq as u64 * POST_DIV as u64;
+        let mult_int = (scaled / REF_FREQ_HZ as u64) as u16;
+        let num = (scaled % REF_FREQ_HZ as u64) as u32;
+
+        PllFields {
+            mult_int,
+            num,
+            denom: REF_FREQ_HZ,
+        }
+    }
+
+    /// Configures and locks the audio PLL to produce `config.freq`.
+    ///
+    /// Called on demand from `FlexcommLowLevel::enable` whenever a Flexcomm selects
+    /// `Clock::AudioPll(freq)` and the PLL isn't already running at `freq`. Exposed so a
+    /// caller can also pre-configure the PLL directly (e.g. to lock it once up front and
+    /// share it across several Flexcomms without each one re-triggering a lock wait).
+    pub fn configure(config: Config) {
+        let fields = solve(config.freq);
+
+        // SAFETY: safe from single executor, init-time only
+        let clkctl0 = unsafe { crate::pac::Clkctl0::steal() };
+
+        clkctl0.audiopll0num().write(|w|
+            // SAFETY: unsafe only used for .bits() call
+            unsafe { w.bits(fields.num) });
+        clkctl0.audiopll0denom().write(|w|
+            // SAFETY: unsafe only used for .bits() call
+            unsafe { w.bits(fields.denom) });
+        clkctl0.audiopll0ctl0().write(|w|
+            // SAFETY: unsafe only used for .bits() call
+            unsafe { w.mult().bits(fields.mult_int) }.bypass().clear_bit());
+
+        // the VCO locks at `freq * POST_DIV`; program the post-divider to bring it back
+        // down to `freq`, matching the divisor `solve` assumed when computing `mult`/`num`.
+        clkctl0.audiopll0pdec().write(|w|
+            // SAFETY: unsafe only used for .bits() call
+            unsafe { w.pdiv().bits(POST_DIV as u8) }.pdiv_req().set_bit());
+
+        while clkctl0.audiopll0ctl0().read().lock().bit_is_clear() {}
+
+        FREQ_HZ.store(config.freq, Ordering::Relaxed);
+    }
+
+    /// Returns the rate currently locked by the audio PLL, or `0` if [`configure`] has not
+    /// been called yet.
+    pub(crate) fn frequency() -> u32 {
+        FREQ_HZ.load(Ordering::Relaxed)
+    }
+}
+
+impl Clock {
+    /// Oscillator/PLL frequency feeding the fractional rate generator for this clock
+    /// selection, or `0` for selections that do not route through the FRG.
+    fn frg_input_freq(self) -> u32 {
+        match self {
+            Clock::FcnFrgMain(_) => master_clk_freq(),
+            Clock::FcnFrgPll(_) => audio_pll_freq(),
+            Clock::FcnFrgSfro(_) => SFRO_FREQ_HZ,
+            Clock::FcnFrgFfro(_) => FFRO_FREQ_HZ,
+            Clock::Sfro | Clock::Ffro | Clock::AudioPll(_) | Clock::Master | Clock::None => 0,
+        }
+    }
+
+    /// Target output frequency requested for an FRG-sourced selection, or `None` otherwise.
+    fn frg_target_freq(self) -> Option<u32> {
+        match self {
+            Clock::FcnFrgMain(f) | Clock::FcnFrgPll(f) | Clock::FcnFrgSfro(f) | Clock::FcnFrgFfro(f) => Some(f),
+            Clock::Sfro | Clock::Ffro | Clock::AudioPll(_) | Clock::Master | Clock::None => None,
+        }
+    }
+
+    /// Ensures the shared audio PLL is in a state this selection can rely on.
+    ///
+    /// Only `Clock::AudioPll` carries enough information to lock the PLL directly, which
+    /// this does on demand. `Clock::FcnFrgPll` only divides down whatever the PLL already
+    /// happens to be running at, so it can't configure the PLL itself — but muxing an FRG
+    /// onto a PLL that was never locked would otherwise silently produce no clock at all
+    /// with no signal of the mistake, so that case is asserted against instead: set the PLL
+    /// up via a `Clock::AudioPll(freq)` selection on any Flexcomm before using `FcnFrgPll`.
+    fn ensure_audio_pll(self) {
+        match self {
+            Clock::AudioPll(freq) => {
+                if audio_pll::frequency() != freq {
+                    audio_pll::configure(audio_pll::Config { freq });
+                }
+            }
+            Clock::FcnFrgPll(_) => assert!(
+                audio_pll::frequency() != 0,
+                "Clock::FcnFrgPll selected but the audio PLL has not been configured; \
+                 select Clock::AudioPll(freq) on some Flexcomm (or call audio_pll::configure) first"
+            ),
+            _ => {}
+        }
+    }
+}
+
+/// Computes the fractional rate generator `MULT` field (`DIV` is held fixed at `0xFF`)
+/// that makes `f_in / (1 + MULT/256)` land as close as possible to `f_out`.
+///
+/// Returns `None` if `f_out` is zero or exceeds `f_in`, or if `f_in / f_out >= 2` — the
+/// FRG alone can only cover the `[1.0, ~1.996)` ratio range; the peripheral's own integer
+/// divider must cover the rest. Callers get to decide how to fall back rather than having
+/// an out-of-range request (trivially reachable from any caller-supplied baud rate) crash
+/// the whole firmware.
+fn frg_mult(f_in: u32, f_out: u32) -> Option<u8> {
+    if f_out == 0 || f_out > f_in || f_in >= f_out.saturating_mul(2) {
+        return None;
+    }
+
+    let numerator = 256_u64 * (f_in - f_out) as u64;
+    let denominator = f_out as u64;
+    let mult = numerator / denominator;
+    let remainder = numerator % denominator;
+    let mult = if remainder * 2 >= denominator { mult + 1 } else { mult };
+
+    Some(mult.min(255) as u8)
+}
+
+/// Computes the FRG `MULT` field for `clk`, or `0` if `clk` doesn't route through the FRG.
+///
+/// Falls back to an undivided FRG (`mult = 0`) instead of panicking when the FRG's input
+/// frequency isn't known yet (e.g. `FcnFrgMain` before the main clock tree is modeled) or
+/// the requested ratio is out of the FRG's range (e.g. a target frequency below half the
+/// input) — the caller still gets a running, if inaccurate, clock rather than a crash.
+fn frg_mult_for(clk: Clock) -> u8 {
+    let Some(f_out) = clk.frg_target_freq() else {
+        return 0;
+    };
+
+    match clk.frg_input_freq() {
+        0 => 0,
+        f_in => frg_mult(f_in, f_out).unwrap_or(0),
+    }
+}
+
+/// Resolves the frequency produced by an FRG fed from the given source, after applying the
+/// FRG's `256/(256+MULT)` factor. Returns `0` if none of the `frg_is_*` selectors are set.
+#[allow(clippy::too_many_arguments)]
+fn resolve_frg_freq(frg_is_main: bool, frg_is_pll: bool, frg_is_sfro: bool, frg_is_ffro: bool, mult: u8) -> u32 {
+    let source_freq = if frg_is_main {
+        master_clk_freq()
+    } else if frg_is_pll {
+        audio_pll_freq()
+    } else if frg_is_sfro {
+        SFRO_FREQ_HZ
+    } else if frg_is_ffro {
+        FFRO_FREQ_HZ
+    } else {
+        return 0;
+    };
+
+    ((source_freq as u64 * 256) / (256 + mult as u64)) as u32
+}
+
 /// do not allow implementation of trait outside this mod
 mod sealed {
     /// trait does not get re-exported outside flexcomm mod, allowing us to safely expose only desired APIs
@@ -109,6 +322,9 @@ pub(crate) trait FlexcommLowLevel: sealed::Sealed + PeripheralType + SysconPerip
     // deconfigure the clock select
     fn disable();
 
+    // read back the clock mux/FRG configuration set by `enable` and resolve the rate it produces
+    fn frequency() -> u32;
+
     // a state associated with a flexcomm device, keeping count
     #[allow(private_interfaces)]
     fn state() -> &'static State;
@@ -130,36 +346,38 @@ macro_rules! impl_flexcomm {
                     }
 
                     fn enable(clk: Clock) -> FlexcommRef {
+                        clk.ensure_audio_pll();
+
                         // SAFETY: safe from single executor
                         let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
 
                         clkctl1.flexcomm($idx).fcfclksel().write(|w| match clk {
                             Clock::Sfro => w.sel().sfro_clk(),
                             Clock::Ffro => w.sel().ffro_clk(),
-                            Clock::AudioPll => w.sel().audio_pll_clk(),
+                            Clock::AudioPll(_) => w.sel().audio_pll_clk(),
                             Clock::Master => w.sel().master_clk(),
-                            Clock::FcnFrgMain => w.sel().fcn_frg_clk(),
-                            Clock::FcnFrgPll => w.sel().fcn_frg_clk(),
-                            Clock::FcnFrgSfro => w.sel().fcn_frg_clk(),
-                            Clock::FcnFrgFfro => w.sel().fcn_frg_clk(),
+                            Clock::FcnFrgMain(_) => w.sel().fcn_frg_clk(),
+                            Clock::FcnFrgPll(_) => w.sel().fcn_frg_clk(),
+                            Clock::FcnFrgSfro(_) => w.sel().fcn_frg_clk(),
+                            Clock::FcnFrgFfro(_) => w.sel().fcn_frg_clk(),
                             Clock::None => w.sel().none(), // no clock? throw an error?
                         });
 
                         clkctl1.flexcomm($idx).frgclksel().write(|w| match clk {
-                            Clock::FcnFrgMain => w.sel().main_clk(),
-                            Clock::FcnFrgPll => w.sel().frg_pll_clk(),
-                            Clock::FcnFrgSfro => w.sel().sfro_clk(),
-                            Clock::FcnFrgFfro => w.sel().ffro_clk(),
+                            Clock::FcnFrgMain(_) => w.sel().main_clk(),
+                            Clock::FcnFrgPll(_) => w.sel().frg_pll_clk(),
+                            Clock::FcnFrgSfro(_) => w.sel().sfro_clk(),
+                            Clock::FcnFrgFfro(_) => w.sel().ffro_clk(),
                             _ => w.sel().none(),    // not using frg ...
                         });
 
-                        // todo: add support for frg div/mult
+                        let mult = frg_mult_for(clk);
                         clkctl1
                             .flexcomm($idx)
                             .frgctl()
                             .write(|w|
                             // SAFETY: unsafe only used for .bits() call
-                            unsafe { w.mult().bits(0) });
+                            unsafe { w.mult().bits(mult) });
 
                         enable_and_reset::<[<FLEXCOMM $idx>]>();
 
@@ -174,6 +392,34 @@ macro_rules! impl_flexcomm {
                         disable::<[<FLEXCOMM $idx>]>();
                     }
 
+                    fn frequency() -> u32 {
+                        // SAFETY: safe from single executor
+                        let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
+
+                        let sel = clkctl1.flexcomm($idx).fcfclksel().read();
+                        if sel.sel().is_sfro_clk() {
+                            SFRO_FREQ_HZ
+                        } else if sel.sel().is_ffro_clk() {
+                            FFRO_FREQ_HZ
+                        } else if sel.sel().is_audio_pll_clk() {
+                            audio_pll_freq()
+                        } else if sel.sel().is_master_clk() {
+                            master_clk_freq()
+                        } else if sel.sel().is_fcn_frg_clk() {
+                            let frg_sel = clkctl1.flexcomm($idx).frgclksel().read();
+                            let mult = clkctl1.flexcomm($idx).frgctl().read().mult().bits();
+                            resolve_frg_freq(
+                                frg_sel.sel().is_main_clk(),
+                                frg_sel.sel().is_frg_pll_clk(),
+                                frg_sel.sel().is_sfro_clk(),
+                                frg_sel.sel().is_ffro_clk(),
+                                mult,
+                            )
+                        } else {
+                            0
+                        }
+                    }
+
                     #[allow(private_interfaces)]
                     fn state() -> &'static State {
                         static STATE: State = State::new();
@@ -199,33 +445,35 @@ impl FlexcommLowLevel for crate::peripherals::FLEXCOMM14 {
     }
 
     fn enable(clk: Clock) -> FlexcommRef {
+        clk.ensure_audio_pll();
+
         // SAFETY: safe from single executor
         let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
 
         clkctl1.fc14fclksel().write(|w| match clk {
             Clock::Sfro => w.sel().sfro_clk(),
             Clock::Ffro => w.sel().ffro_clk(),
-            Clock::AudioPll => w.sel().audio_pll_clk(),
+            Clock::AudioPll(_) => w.sel().audio_pll_clk(),
             Clock::Master => w.sel().master_clk(),
-            Clock::FcnFrgMain => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgPll => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgSfro => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgFfro => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgMain(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgPll(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgSfro(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgFfro(_) => w.sel().fcn_frg_clk(),
             Clock::None => w.sel().none(), // no clock? throw an error?
         });
 
         clkctl1.frg14clksel().write(|w| match clk {
-            Clock::FcnFrgMain => w.sel().main_clk(),
-            Clock::FcnFrgPll => w.sel().frg_pll_clk(),
-            Clock::FcnFrgSfro => w.sel().sfro_clk(),
-            Clock::FcnFrgFfro => w.sel().ffro_clk(),
+            Clock::FcnFrgMain(_) => w.sel().main_clk(),
+            Clock::FcnFrgPll(_) => w.sel().frg_pll_clk(),
+            Clock::FcnFrgSfro(_) => w.sel().sfro_clk(),
+            Clock::FcnFrgFfro(_) => w.sel().ffro_clk(),
             _ => w.sel().none(), // not using frg ...
         });
 
-        // todo: add support for frg div/mult
+        let mult = frg_mult_for(clk);
         clkctl1.frg14ctl().write(|w|
                 // SAFETY: unsafe only used for .bits() call
-                unsafe { w.mult().bits(0) });
+                unsafe { w.mult().bits(mult) });
 
         enable_and_reset::<FLEXCOMM14>();
 
@@ -240,6 +488,34 @@ impl FlexcommLowLevel for crate::peripherals::FLEXCOMM14 {
         disable::<FLEXCOMM14>();
     }
 
+    fn frequency() -> u32 {
+        // SAFETY: safe from single executor
+        let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
+
+        let sel = clkctl1.fc14fclksel().read();
+        if sel.sel().is_sfro_clk() {
+            SFRO_FREQ_HZ
+        } else if sel.sel().is_ffro_clk() {
+            FFRO_FREQ_HZ
+        } else if sel.sel().is_audio_pll_clk() {
+            audio_pll_freq()
+        } else if sel.sel().is_master_clk() {
+            master_clk_freq()
+        } else if sel.sel().is_fcn_frg_clk() {
+            let frg_sel = clkctl1.frg14clksel().read();
+            let mult = clkctl1.frg14ctl().read().mult().bits();
+            resolve_frg_freq(
+                frg_sel.sel().is_main_clk(),
+                frg_sel.sel().is_frg_pll_clk(),
+                frg_sel.sel().is_sfro_clk(),
+                frg_sel.sel().is_ffro_clk(),
+                mult,
+            )
+        } else {
+            0
+        }
+    }
+
     #[allow(private_interfaces)]
     fn state() -> &'static State {
         static STATE: State = State::new();
@@ -258,31 +534,33 @@ impl FlexcommLowLevel for crate::peripherals::FLEXCOMM15 {
     }
 
     fn enable(clk: Clock) -> FlexcommRef {
+        clk.ensure_audio_pll();
+
         // SAFETY: safe from single executor
         let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
 
         clkctl1.fc15fclksel().write(|w| match clk {
             Clock::Sfro => w.sel().sfro_clk(),
             Clock::Ffro => w.sel().ffro_clk(),
-            Clock::AudioPll => w.sel().audio_pll_clk(),
+            Clock::AudioPll(_) => w.sel().audio_pll_clk(),
             Clock::Master => w.sel().master_clk(),
-            Clock::FcnFrgMain => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgPll => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgSfro => w.sel().fcn_frg_clk(),
-            Clock::FcnFrgFfro => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgMain(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgPll(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgSfro(_) => w.sel().fcn_frg_clk(),
+            Clock::FcnFrgFfro(_) => w.sel().fcn_frg_clk(),
             Clock::None => w.sel().none(), // no clock? throw an error?
         });
         clkctl1.frg15clksel().write(|w| match clk {
-            Clock::FcnFrgMain => w.sel().main_clk(),
-            Clock::FcnFrgPll => w.sel().frg_pll_clk(),
-            Clock::FcnFrgSfro => w.sel().sfro_clk(),
-            Clock::FcnFrgFfro => w.sel().ffro_clk(),
+            Clock::FcnFrgMain(_) => w.sel().main_clk(),
+            Clock::FcnFrgPll(_) => w.sel().frg_pll_clk(),
+            Clock::FcnFrgSfro(_) => w.sel().sfro_clk(),
+            Clock::FcnFrgFfro(_) => w.sel().ffro_clk(),
             _ => w.sel().none(), // not using frg ...
         });
-        // todo: add support for frg div/mult
+        let mult = frg_mult_for(clk);
         clkctl1.frg15ctl().write(|w|
                 // SAFETY: unsafe only used for .bits() call
-                unsafe { w.mult().bits(0) });
+                unsafe { w.mult().bits(mult) });
 
         enable_and_reset::<FLEXCOMM15>();
 
@@ -297,6 +575,34 @@ impl FlexcommLowLevel for crate::peripherals::FLEXCOMM15 {
         disable::<FLEXCOMM15>();
     }
 
+    fn frequency() -> u32 {
+        // SAFETY: safe from single executor
+        let clkctl1 = unsafe { crate::pac::Clkctl1::steal() };
+
+        let sel = clkctl1.fc15fclksel().read();
+        if sel.sel().is_sfro_clk() {
+            SFRO_FREQ_HZ
+        } else if sel.sel().is_ffro_clk() {
+            FFRO_FREQ_HZ
+        } else if sel.sel().is_audio_pll_clk() {
+            audio_pll_freq()
+        } else if sel.sel().is_master_clk() {
+            master_clk_freq()
+        } else if sel.sel().is_fcn_frg_clk() {
+            let frg_sel = clkctl1.frg15clksel().read();
+            let mult = clkctl1.frg15ctl().read().mult().bits();
+            resolve_frg_freq(
+                frg_sel.sel().is_main_clk(),
+                frg_sel.sel().is_frg_pll_clk(),
+                frg_sel.sel().is_sfro_clk(),
+                frg_sel.sel().is_ffro_clk(),
+                mult,
+            )
+        } else {
+            0
+        }
+    }
+
     #[allow(private_interfaces)]
     fn state() -> &'static State {
         static STATE: State = State::new();
@@ -358,3 +664,6 @@ into_mode!(
     FLEXCOMM6,
     FLEXCOMM7
 );
+
+// The I2S driver built on `i2s_transmit`/`i2s_receive` (pin marker traits, `I2sFullDuplex`)
+// lives in `crate::i2s`, alongside the other per-protocol driver surfaces, rather than here.
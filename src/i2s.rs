@@ -0,0 +1,90 @@
+//! I2S driver built on top of the Flexcomm transmit/receive modes
+
+use crate::flexcomm::{Clock, FlexcommLowLevel, FlexcommRef, IntoI2sReceive, IntoI2sTransmit};
+use crate::pac;
+
+/// Marker trait for a pin wired as the shared bit clock (SCK) of an I2S configuration.
+pub trait I2sSckPin<T: FlexcommLowLevel> {}
+
+/// Marker trait for a pin wired as the shared frame sync (WS) of an I2S configuration.
+pub trait I2sWsPin<T: FlexcommLowLevel> {}
+
+/// Marker trait for a pin wired as the transmit data line (SD-out) of an I2S configuration.
+pub trait I2sSdoPin<T: FlexcommLowLevel> {}
+
+/// Marker trait for a pin wired as the receive data line (SD-in) of an I2S configuration.
+pub trait I2sSdiPin<T: FlexcommLowLevel> {}
+
+/// Marker trait for a pin wired as the master clock (MCLK) of an I2S configuration.
+pub trait I2sMclkPin<T: FlexcommLowLevel> {}
+
+/// Full-duplex I2S driver.
+///
+/// The Flexcomm PERSEL encoding only has distinct transmit and receive I2S personas, no
+/// combined one — a single instance can run either direction, not both at once. Full
+/// duplex is done the way the silicon actually supports it: one Flexcomm configured for
+/// `i2s_transmit` and a second for `i2s_receive`, wired to the same shared bit clock and
+/// frame sync (and, optionally, master clock) with independent data lines. Both instances
+/// are enabled from the same `Clock` selection; no signal-sharing (`SELECT`) routing between
+/// them is programmed. Holds the pins for as long as the driver is alive, so they can't be
+/// reclaimed out from under the still-wired peripherals.
+pub struct I2sFullDuplex<Sck, Ws, Sdo, Sdi, Mclk> {
+    tx: FlexcommRef,
+    rx: FlexcommRef,
+    tx_reg: fn() -> &'static pac::flexcomm0::RegisterBlock,
+    rx_reg: fn() -> &'static pac::flexcomm0::RegisterBlock,
+    _sck: Sck,
+    _ws: Ws,
+    _sdo: Sdo,
+    _sdi: Sdi,
+    _mclk: Option<Mclk>,
+}
+
+impl<Sck, Ws, Sdo, Sdi, Mclk> I2sFullDuplex<Sck, Ws, Sdo, Sdi, Mclk> {
+    /// Enables `Tx` and `Rx` with `clk` and configures them as the transmit and receive
+    /// halves of a full-duplex I2S pair.
+    ///
+    /// `sck`/`ws` are the bit clock and frame sync shared between both instances, `sdo`/`sdi`
+    /// the independent transmit/receive data lines, and `mclk` an optional master clock for
+    /// driving an external codec.
+    pub fn new<Tx, Rx>(clk: Clock, sck: Sck, ws: Ws, sdo: Sdo, sdi: Sdi, mclk: Option<Mclk>) -> Self
+    where
+        Tx: FlexcommLowLevel + IntoI2sTransmit,
+        Rx: FlexcommLowLevel + IntoI2sReceive,
+        Sck: I2sSckPin<Tx> + I2sSckPin<Rx>,
+        Ws: I2sWsPin<Tx> + I2sWsPin<Rx>,
+        Sdo: I2sSdoPin<Tx>,
+        Sdi: I2sSdiPin<Rx>,
+        Mclk: I2sMclkPin<Tx>,
+    {
+        let tx = Tx::enable(clk);
+        Tx::into_i2s_transmit();
+        let rx = Rx::enable(clk);
+        Rx::into_i2s_receive();
+        Self {
+            tx,
+            rx,
+            tx_reg: Tx::reg,
+            rx_reg: Rx::reg,
+            _sck: sck,
+            _ws: ws,
+            _sdo: sdo,
+            _sdi: sdi,
+            _mclk: mclk,
+        }
+    }
+
+    /// Blocks until the transmit FIFO has room, then queues `sample` for the SD-out line.
+    pub fn write(&mut self, sample: u32) {
+        while (self.tx_reg)().fifostat().read().txnotfull().bit_is_clear() {}
+        (self.tx_reg)().fifowr().write(|w|
+            // SAFETY: unsafe only used for .bits() call
+            unsafe { w.txdata().bits(sample) });
+    }
+
+    /// Blocks until the receive FIFO has data, then returns the next sample from SD-in.
+    pub fn read(&mut self) -> u32 {
+        while (self.rx_reg)().fifostat().read().rxnotempty().bit_is_clear() {}
+        (self.rx_reg)().fiford().read().rxdata().bits()
+    }
+}